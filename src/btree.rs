@@ -1,36 +1,79 @@
 use std::{
     borrow::Borrow,
-    collections::BTreeSet,
-    mem::swap,
-    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign},
+    collections::{btree_set, BTreeSet},
 };
 
-use crate::convert::FromComplement;
+use crate::{convert::FromComplement, set::InfSet};
 
 /// A set that can not only represent the union of elements, but also the complement of a
-/// theoretical infinite set.
+/// theoretical infinite set, backed by a [`BTreeSet`].
 ///
-/// It is assumed, that the set of elements, representable by `T`, is infinite.
-/// Say we choose `bool` for our element type which only has `true` and `false` as possible
-/// elements:
-/// Since we know, that the set of possible elements for `bool` is not infinite, one could assume
-/// that `InvBTreeSet::from_complement([false, true])` should be equal to the empty set.
-/// This however is not the case, as an empty complement is instead seen as containing literally
-/// "everything", and not just everything representable by `T`.
+/// See [`InfSet`] for the full set of guarantees and caveats that come with the "infinite" part.
+pub type InfBTreeSet<T> = InfSet<BTreeSet<T>>;
+
+/// The result of a lazy set operation between two [`InfBTreeSet`]s.
+///
+/// Some set operations (e.g. intersecting two [`Complement`]s) produce a cofinite result, which
+/// cannot be enumerated; in that case the iterator instead yields the *excluded* elements.
+///
+/// [`Complement`]: InfSet::Complement
+#[derive(Debug, Clone)]
+pub enum InfIter<I> {
+    /// The result is finite. Iterates the elements that are part of the result.
+    Finite(I),
+    /// The result is cofinite. Iterates the elements that are *not* part of the result.
+    Cofinite(I),
+}
+
+impl<I> InfIter<I> {
+    /// Returns `true` if the result of the set operation is finite, i.e. if [`Iterator::next`]
+    /// yields the elements that are actually part of the result.
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Self::Finite(_))
+    }
+
+    /// Returns `true` if the result of the set operation is cofinite, i.e. if [`Iterator::next`]
+    /// yields the elements that are *excluded* from the result.
+    pub fn is_cofinite(&self) -> bool {
+        matches!(self, Self::Cofinite(_))
+    }
+}
+
+impl<I: Iterator> Iterator for InfIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (Self::Finite(iter) | Self::Cofinite(iter)) = self;
+        iter.next()
+    }
+}
+
+/// The combination of [`BTreeSet`]'s own lazy set-operation iterators, used as the inner
+/// iterator of an [`InfIter`] returned by [`InfBTreeSet::union`], [`InfBTreeSet::intersection`]
+/// and [`InfBTreeSet::difference`].
 ///
-/// With this in mind, it usually makes more sense to choose a type that actually does have an
-/// infinite number of possible elements.
-/// Examples for this would be a recursive structure or even just a [`Vec<T>`].
+/// Which variant is used depends on the combination of [`Union`]/[`Complement`] operands; see the
+/// respective method for the exact algebra.
 ///
-/// It can also make sense to use integers (or floats), which might not have an infinite number of
-/// actually representable values, but are usually assumed to be a representation of the entire set
-/// of all integers up to infinity.
-#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub enum InfBTreeSet<T> {
-    /// Elements that are part of the set.
-    Union(BTreeSet<T>),
-    /// Elements that are *not* part of the set.
-    Complement(BTreeSet<T>),
+/// [`Union`]: InfSet::Union
+/// [`Complement`]: InfSet::Complement
+#[derive(Debug, Clone)]
+pub enum SetOpIter<'a, T> {
+    Union(btree_set::Union<'a, T>),
+    Intersection(btree_set::Intersection<'a, T>),
+    Difference(btree_set::Difference<'a, T>),
+}
+
+impl<'a, T: Ord> Iterator for SetOpIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self {
+            Self::Union(iter) => iter.next(),
+            Self::Intersection(iter) => iter.next(),
+            Self::Difference(iter) => iter.next(),
+        }
+    }
 }
 
 impl<T> InfBTreeSet<T> {
@@ -84,8 +127,8 @@ impl<T> InfBTreeSet<T> {
     /// assert!(set.is_empty());
     /// ```
     ///
-    /// [`Union`]: InfBTreeSet::Union
-    /// [`Complement`]: InfBTreeSet::Complement
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
     pub fn clear(&mut self) {
         *self = Self::new();
     }
@@ -114,186 +157,367 @@ impl<T> InfBTreeSet<T> {
         Q: Ord + ?Sized,
     {
         match self {
-            InfBTreeSet::Union(union) => union.contains(value),
-            InfBTreeSet::Complement(complement) => !complement.contains(value),
+            Self::Union(union) => union.contains(value),
+            Self::Complement(complement) => !complement.contains(value),
         }
     }
 
-    /// Returns `true` if [`self`] has no elements in common with `other`.
+    /// Visits the elements of `self` and `other` in ascending order, yielding the union if it is
+    /// finite, or the excluded elements of the (cofinite) complement of the union otherwise.
     ///
-    /// A [`Union`] and [`Complement`] are disjoint, if the [`Union`] is a subset of the
-    /// [`Complement`]'s elements.
-    ///
-    /// Two [`Complement`]s will never be disjoint, as they always have an overlap because of
-    /// their "infinite" nature.
+    /// The union is cofinite if at least one of `self` and `other` is a [`Complement`].
     ///
     /// # Examples
     ///
     /// ```
     /// use infset::{btree::InfBTreeSet, convert::FromComplement};
     ///
-    /// // Unions are disjoint if there is no overlap:
-    /// let union1 = InfBTreeSet::from([1]);
-    /// assert!(!union1.is_disjoint(&union1));
+    /// let a = InfBTreeSet::from([1, 2]);
+    /// let b = InfBTreeSet::from([2, 3]);
+    /// let union: Vec<_> = a.union(&b).collect();
+    /// assert_eq!(union, [&1, &2, &3]);
+    ///
+    /// let all = InfBTreeSet::<i32>::all();
+    /// assert!(all.union(&all).is_cofinite());
+    /// ```
+    ///
+    /// [`Complement`]: InfSet::Complement
+    pub fn union<'a>(&'a self, other: &'a InfBTreeSet<T>) -> InfIter<SetOpIter<'a, T>>
+    where
+        T: Ord,
+    {
+        match (self, other) {
+            (Self::Union(this), Self::Union(other)) => {
+                InfIter::Finite(SetOpIter::Union(this.union(other)))
+            }
+            (Self::Union(union), Self::Complement(complement))
+            | (Self::Complement(complement), Self::Union(union)) => {
+                InfIter::Cofinite(SetOpIter::Difference(complement.difference(union)))
+            }
+            (Self::Complement(this), Self::Complement(other)) => {
+                InfIter::Cofinite(SetOpIter::Intersection(this.intersection(other)))
+            }
+        }
+    }
+
+    /// Visits the elements of `self` and `other` in ascending order, yielding the intersection if
+    /// it is finite, or the excluded elements of the (cofinite) complement of the intersection
+    /// otherwise.
     ///
-    /// let union2 = InfBTreeSet::from([2]);
-    /// assert!(union1.is_disjoint(&union2));
+    /// The intersection is only cofinite if both `self` and `other` are [`Complement`]s.
     ///
-    /// // A union and a complement are disjoint, if the union is a subset of the complement's
-    /// // values:
-    /// let complement1 = InfBTreeSet::from_complement([1]);
-    /// assert!(union1.is_disjoint(&complement1));
+    /// # Examples
     ///
-    /// let complement2 = InfBTreeSet::from_complement([2]);
-    /// assert!(!union1.is_disjoint(&complement2));
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
     ///
-    /// // Complements always overlap because of their "infinite" nature:
-    /// let all = InfBTreeSet::<u32>::all();
-    /// assert!(!all.is_disjoint(&all));
+    /// let a = InfBTreeSet::from([1, 2]);
+    /// let b = InfBTreeSet::from([2, 3]);
+    /// let intersection: Vec<_> = a.intersection(&b).collect();
+    /// assert_eq!(intersection, [&2]);
     /// ```
     ///
-    /// [`Union`]: InfBTreeSet::Union
-    /// [`Complement`]: InfBTreeSet::Complement
-    pub fn is_disjoint(&self, other: &InfBTreeSet<T>) -> bool
+    /// [`Complement`]: InfSet::Complement
+    pub fn intersection<'a>(&'a self, other: &'a InfBTreeSet<T>) -> InfIter<SetOpIter<'a, T>>
     where
         T: Ord,
     {
         match (self, other) {
-            (InfBTreeSet::Union(this), InfBTreeSet::Union(other)) => this.is_disjoint(other),
-            (InfBTreeSet::Union(union), InfBTreeSet::Complement(complement))
-            | (InfBTreeSet::Complement(complement), InfBTreeSet::Union(union)) => {
-                union.is_subset(complement)
+            (Self::Union(this), Self::Union(other)) => {
+                InfIter::Finite(SetOpIter::Intersection(this.intersection(other)))
+            }
+            (Self::Union(union), Self::Complement(complement))
+            | (Self::Complement(complement), Self::Union(union)) => {
+                InfIter::Finite(SetOpIter::Difference(union.difference(complement)))
+            }
+            (Self::Complement(this), Self::Complement(other)) => {
+                InfIter::Cofinite(SetOpIter::Union(this.union(other)))
             }
-            (InfBTreeSet::Complement(_), InfBTreeSet::Complement(_)) => false,
         }
     }
 
-    /// Returns `true` if the set is a subset of another, i.e., `other` contains at least all the
-    /// elements in `self`.
-    pub fn is_subset(&self, other: &InfBTreeSet<T>) -> bool
+    /// Visits the elements of `self` that are not in `other` in ascending order, yielding the
+    /// difference if it is finite, or the excluded elements of the (cofinite) complement of the
+    /// difference otherwise.
+    ///
+    /// The difference is only cofinite if `self` is a [`Complement`] and `other` is a [`Union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let a = InfBTreeSet::from([1, 2]);
+    /// let b = InfBTreeSet::from([2, 3]);
+    /// let difference: Vec<_> = a.difference(&b).collect();
+    /// assert_eq!(difference, [&1]);
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn difference<'a>(&'a self, other: &'a InfBTreeSet<T>) -> InfIter<SetOpIter<'a, T>>
     where
         T: Ord,
     {
         match (self, other) {
-            (InfBTreeSet::Union(this), InfBTreeSet::Union(other)) => this.is_subset(other),
-            (InfBTreeSet::Union(_), InfBTreeSet::Complement(_)) => todo!(),
-            (InfBTreeSet::Complement(_), InfBTreeSet::Union(_)) => todo!(),
-            (InfBTreeSet::Complement(this), InfBTreeSet::Complement(other)) => {
-                other.is_subset(this)
+            (Self::Union(this), Self::Union(other)) => {
+                InfIter::Finite(SetOpIter::Difference(this.difference(other)))
+            }
+            (Self::Union(this), Self::Complement(other)) => {
+                InfIter::Finite(SetOpIter::Intersection(this.intersection(other)))
+            }
+            (Self::Complement(this), Self::Union(other)) => {
+                InfIter::Cofinite(SetOpIter::Union(this.union(other)))
+            }
+            (Self::Complement(this), Self::Complement(other)) => {
+                InfIter::Finite(SetOpIter::Difference(other.difference(this)))
             }
         }
     }
 
-    /// Returns `true` if the set is a superset of another, i.e., `self` contains at least all the
-    /// elements in `other`.
-    pub fn is_superset(&self, other: &InfBTreeSet<T>) -> bool
+    /// Visits the elements that are in `self` or `other` but not both, in ascending order,
+    /// yielding the symmetric difference if it is finite, or the excluded elements of the
+    /// (cofinite) complement of the symmetric difference otherwise.
+    ///
+    /// The symmetric difference is cofinite exactly when `self` and `other` are not both the same
+    /// variant, i.e. when exactly one of them is a [`Complement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let a = InfBTreeSet::from([1, 2]);
+    /// let b = InfBTreeSet::from([2, 3]);
+    /// let symmetric_difference: Vec<_> = a.symmetric_difference(&b).collect();
+    /// assert_eq!(symmetric_difference, [&1, &3]);
+    /// ```
+    ///
+    /// [`Complement`]: InfSet::Complement
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a InfBTreeSet<T>,
+    ) -> InfIter<btree_set::SymmetricDifference<'a, T>>
     where
         T: Ord,
     {
-        other.is_subset(self)
-    }
-
-    // ---
-
-    pub fn is_empty(&self) -> bool {
-        self.as_union().map_or(false, |union| union.is_empty())
-    }
-
-    pub fn is_all(&self) -> bool {
-        self.as_complement()
-            .map_or(false, |complement| complement.is_empty())
-    }
-
-    pub fn is_union(&self) -> bool {
-        matches!(self, Self::Union(_))
-    }
-
-    pub fn as_union(&self) -> Option<&BTreeSet<T>> {
-        if let Self::Union(v) = self {
-            Some(v)
-        } else {
-            None
+        match (self, other) {
+            (Self::Union(this), Self::Union(other)) => {
+                InfIter::Finite(this.symmetric_difference(other))
+            }
+            (Self::Union(this), Self::Complement(other))
+            | (Self::Complement(this), Self::Union(other)) => {
+                InfIter::Cofinite(this.symmetric_difference(other))
+            }
+            (Self::Complement(this), Self::Complement(other)) => {
+                InfIter::Finite(this.symmetric_difference(other))
+            }
         }
     }
 
-    pub fn try_into_union(self) -> Result<BTreeSet<T>, Self> {
-        if let Self::Union(v) = self {
-            Ok(v)
-        } else {
-            Err(self)
+    /// Removes a value from the set. Returns whether the value was previously contained.
+    ///
+    /// In a [`Union`], this removes the value from the stored set. In a [`Complement`], this
+    /// instead *inserts* the value into the stored set, since a [`Complement`] considers a value
+    /// contained exactly when it is *not* in the stored set.
+    ///
+    /// The value may be any borrowed form of the set's element type, but the ordering on the
+    /// borrowed form *must* match the ordering on the element type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let mut union = InfBTreeSet::from([1, 2]);
+    /// assert!(union.remove(&1));
+    /// assert!(!union.contains(&1));
+    ///
+    /// let mut complement = InfBTreeSet::from_complement([1]);
+    /// assert!(complement.remove(&2));
+    /// assert!(!complement.contains(&2));
+    /// ```
+    ///
+    /// Unlike [`contains`](Self::contains)/[`get`](Self::get)/[`take`](Self::take), `Q` must also
+    /// be [`ToOwned`] with `Owned = T`: removing from a [`Complement`] means *inserting* `value`
+    /// into the stored set of exclusions, which needs an owned `T` to insert, not just a borrow.
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        match self {
+            Self::Union(set) => set.remove(value),
+            Self::Complement(set) => {
+                let contained = !set.contains(value);
+                if contained {
+                    set.insert(value.to_owned());
+                }
+                contained
+            }
         }
     }
 
-    pub fn is_complement(&self) -> bool {
-        matches!(self, Self::Complement(_))
-    }
-
-    pub fn as_complement(&self) -> Option<&BTreeSet<T>> {
-        if let Self::Complement(v) = self {
-            Some(v)
-        } else {
-            None
+    /// Returns a reference to the element in the set, if any, that is equal to the value.
+    ///
+    /// Since a [`Complement`] only stores the elements that are *not* part of the set, there is
+    /// no stored element to borrow for a value it logically contains, so this always returns
+    /// [`None`] for a [`Complement`].
+    ///
+    /// The value may be any borrowed form of the set's element type, but the ordering on the
+    /// borrowed form *must* match the ordering on the element type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let union = InfBTreeSet::from([42]);
+    /// assert_eq!(union.get(&42), Some(&42));
+    /// assert_eq!(union.get(&0), None);
+    ///
+    /// let complement = InfBTreeSet::from_complement([42]);
+    /// assert_eq!(complement.get(&0), None);
+    /// ```
+    ///
+    /// [`Complement`]: InfSet::Complement
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Union(set) => set.get(value),
+            Self::Complement(_) => None,
         }
     }
 
-    pub fn try_into_complement(self) -> Result<BTreeSet<T>, Self> {
-        if let Self::Complement(v) = self {
-            Ok(v)
-        } else {
-            Err(self)
+    /// Removes and returns the element in the set, if any, that is equal to the value.
+    ///
+    /// Since a [`Complement`] only stores the elements that are *not* part of the set, there is
+    /// no stored element to take ownership of for a value it logically contains, so this always
+    /// returns [`None`] for a [`Complement`].
+    ///
+    /// The value may be any borrowed form of the set's element type, but the ordering on the
+    /// borrowed form *must* match the ordering on the element type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let mut union = InfBTreeSet::from([42]);
+    /// assert_eq!(union.take(&42), Some(42));
+    /// assert!(!union.contains(&42));
+    ///
+    /// let mut complement = InfBTreeSet::from_complement([42]);
+    /// assert_eq!(complement.take(&0), None);
+    /// ```
+    ///
+    /// [`Complement`]: InfSet::Complement
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Union(set) => set.take(value),
+            Self::Complement(_) => None,
         }
     }
 
-    pub fn as_storage(&self) -> &BTreeSet<T> {
-        let (Self::Union(storage) | Self::Complement(storage)) = self;
-        storage
-    }
-
-    pub fn into_storage(self) -> BTreeSet<T> {
-        let (Self::Union(storage) | Self::Complement(storage)) = self;
-        storage
+    /// Adds a value to the set, replacing the existing element, if any, that is equal to the
+    /// value. Returns the replaced element.
+    ///
+    /// In a [`Union`], this delegates directly to the stored set. In a [`Complement`], adding a
+    /// value means removing it from the stored set of excluded elements; since there is no
+    /// element stored for a value while it is contained, this always returns [`None`] for a
+    /// [`Complement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let mut union = InfBTreeSet::from([42]);
+    /// assert_eq!(union.replace(42), Some(42));
+    ///
+    /// let mut complement = InfBTreeSet::from_complement([42]);
+    /// assert_eq!(complement.replace(42), None);
+    /// assert!(complement.contains(&42));
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn replace(&mut self, value: T) -> Option<T>
+    where
+        T: Ord,
+    {
+        match self {
+            Self::Union(set) => set.replace(value),
+            Self::Complement(set) => {
+                set.remove(&value);
+                None
+            }
+        }
     }
 
-    pub fn insert(&mut self, value: T)
+    /// Inserts a value into the set if it is not already contained, then returns a reference to
+    /// the equal element now stored in the set.
+    ///
+    /// Since a [`Complement`] does not store the elements it logically contains (it stores
+    /// exactly the excluded ones), there is no element to return a reference to once `value`
+    /// becomes contained this way, so this always returns [`None`] for a [`Complement`], in line
+    /// with [`get`](Self::get)/[`take`](Self::take)/[`replace`](Self::replace).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let mut union = InfBTreeSet::new();
+    /// assert_eq!(union.get_or_insert(42), Some(&42));
+    ///
+    /// let mut complement = InfBTreeSet::from_complement([42]);
+    /// assert_eq!(complement.get_or_insert(42), None);
+    /// assert!(complement.contains(&42));
+    /// ```
+    ///
+    /// [`Complement`]: InfSet::Complement
+    pub fn get_or_insert(&mut self, value: T) -> Option<&T>
     where
-        T: Ord,
+        T: Ord + Clone,
     {
         match self {
-            InfBTreeSet::Union(set) => {
+            Self::Union(set) => {
+                let key = value.clone();
                 set.insert(value);
+                Some(set.get(&key).expect("value was just inserted"))
             }
-            InfBTreeSet::Complement(set) => {
+            Self::Complement(set) => {
                 set.remove(&value);
+                None
             }
         }
     }
 }
 
-impl<T> From<BTreeSet<T>> for InfBTreeSet<T> {
-    fn from(v: BTreeSet<T>) -> Self {
-        Self::Union(v)
-    }
-}
-
-impl<T: Ord, const N: usize> From<[T; N]> for InfBTreeSet<T> {
+impl<T: Ord + Clone, const N: usize> From<[T; N]> for InfBTreeSet<T> {
     fn from(arr: [T; N]) -> Self {
         Self::from(BTreeSet::from(arr))
     }
 }
 
-impl<T: Ord> FromIterator<T> for InfBTreeSet<T> {
+impl<T: Ord + Clone> FromIterator<T> for InfBTreeSet<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self::from(BTreeSet::from_iter(iter))
     }
 }
 
-impl<T> FromComplement<BTreeSet<T>> for InfBTreeSet<T> {
-    fn from_complement(v: BTreeSet<T>) -> Self {
-        Self::Complement(v)
-    }
-}
-
-impl<T: Ord, const N: usize> FromComplement<[T; N]> for InfBTreeSet<T> {
+impl<T: Ord + Clone, const N: usize> FromComplement<[T; N]> for InfBTreeSet<T> {
     fn from_complement(arr: [T; N]) -> Self {
         Self::from_complement(BTreeSet::from(arr))
     }
@@ -306,191 +530,3 @@ impl<T> TryFrom<InfBTreeSet<T>> for BTreeSet<T> {
         value.try_into_union()
     }
 }
-
-impl<T: std::fmt::Debug> std::fmt::Debug for InfBTreeSet<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_complement() {
-            write!(f, "!")?;
-        }
-        self.as_storage().fmt(f)
-    }
-}
-
-impl<T: Default> Default for InfBTreeSet<T> {
-    /// Creates an empty `InfBTreeSet`.
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl<T: Ord + Clone> BitOr for InfBTreeSet<T> {
-    type Output = Self;
-
-    fn bitor(mut self, rhs: Self) -> Self::Output {
-        self |= rhs;
-        self
-    }
-}
-
-impl<T: Ord + Clone> BitOr<&InfBTreeSet<T>> for InfBTreeSet<T> {
-    type Output = Self;
-
-    fn bitor(mut self, rhs: &InfBTreeSet<T>) -> Self::Output {
-        self |= rhs;
-        self
-    }
-}
-
-impl<T: Ord + Clone> BitOr for &InfBTreeSet<T> {
-    type Output = InfBTreeSet<T>;
-
-    fn bitor(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (InfBTreeSet::Union(lhs), InfBTreeSet::Union(rhs)) => InfBTreeSet::Union(lhs | rhs),
-            (InfBTreeSet::Union(union), InfBTreeSet::Complement(complement))
-            | (InfBTreeSet::Complement(complement), InfBTreeSet::Union(union)) => {
-                InfBTreeSet::Complement(complement - union)
-            }
-            (InfBTreeSet::Complement(lhs), InfBTreeSet::Complement(rhs)) => {
-                InfBTreeSet::Complement(lhs & rhs)
-            }
-        }
-    }
-}
-
-impl<T: Ord + Clone> BitOr<InfBTreeSet<T>> for &InfBTreeSet<T> {
-    type Output = InfBTreeSet<T>;
-
-    fn bitor(self, rhs: InfBTreeSet<T>) -> Self::Output {
-        rhs | self
-    }
-}
-
-impl<T: Ord + Clone> BitOrAssign for InfBTreeSet<T> {
-    fn bitor_assign(&mut self, mut rhs: Self) {
-        if let (InfBTreeSet::Union(_), InfBTreeSet::Complement(_)) = (&self, &rhs) {
-            swap(self, &mut rhs);
-        }
-        match (self, rhs) {
-            (Self::Union(lhs), Self::Union(mut rhs)) => {
-                lhs.append(&mut rhs);
-            }
-            (Self::Complement(complement), Self::Union(union)) => {
-                complement.retain(|ty| !union.contains(ty));
-            }
-            (Self::Union(_), Self::Complement(_)) => unreachable!(),
-            (Self::Complement(lhs), Self::Complement(rhs)) => {
-                lhs.retain(|ty| rhs.contains(ty));
-            }
-        }
-    }
-}
-
-impl<T: Ord + Clone> BitOrAssign<&InfBTreeSet<T>> for InfBTreeSet<T> {
-    fn bitor_assign(&mut self, rhs: &InfBTreeSet<T>) {
-        if let (InfBTreeSet::Union(union), InfBTreeSet::Complement(complement)) = (&self, rhs) {
-            let mut complement = complement.clone();
-            complement.retain(|ty| !union.contains(ty));
-            *self = Self::Complement(complement);
-            return;
-        }
-        match (self, rhs) {
-            (Self::Union(lhs), Self::Union(rhs)) => {
-                lhs.append(&mut rhs.clone());
-            }
-            (Self::Union(_), Self::Complement(_)) => unreachable!(),
-            (Self::Complement(complement), Self::Union(union)) => {
-                complement.retain(|ty| !union.contains(ty));
-            }
-            (Self::Complement(lhs), Self::Complement(rhs)) => {
-                lhs.retain(|ty| rhs.contains(ty));
-            }
-        }
-    }
-}
-
-impl<T: Ord + Clone> BitAnd for InfBTreeSet<T> {
-    type Output = Self;
-
-    fn bitand(mut self, rhs: Self) -> Self::Output {
-        self &= rhs;
-        self
-    }
-}
-
-impl<T: Ord + Clone> BitAnd<&InfBTreeSet<T>> for InfBTreeSet<T> {
-    type Output = Self;
-
-    fn bitand(mut self, rhs: &InfBTreeSet<T>) -> Self::Output {
-        self &= rhs;
-        self
-    }
-}
-
-impl<T: Ord + Clone> BitAnd for &InfBTreeSet<T> {
-    type Output = InfBTreeSet<T>;
-
-    fn bitand(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (InfBTreeSet::Union(lhs), InfBTreeSet::Union(rhs)) => InfBTreeSet::Union(lhs & rhs),
-            (InfBTreeSet::Union(union), InfBTreeSet::Complement(complement))
-            | (InfBTreeSet::Complement(complement), InfBTreeSet::Union(union)) => {
-                InfBTreeSet::Complement(union - complement)
-            }
-            (InfBTreeSet::Complement(lhs), InfBTreeSet::Complement(rhs)) => {
-                InfBTreeSet::Complement(lhs | rhs)
-            }
-        }
-    }
-}
-
-impl<T: Ord + Clone> BitAnd<InfBTreeSet<T>> for &InfBTreeSet<T> {
-    type Output = InfBTreeSet<T>;
-
-    fn bitand(self, rhs: InfBTreeSet<T>) -> Self::Output {
-        rhs & self
-    }
-}
-
-impl<T: Ord + Clone> BitAndAssign for InfBTreeSet<T> {
-    fn bitand_assign(&mut self, mut rhs: Self) {
-        if let (InfBTreeSet::Complement(_), InfBTreeSet::Union(_)) = (&self, &rhs) {
-            swap(self, &mut rhs);
-        }
-        match (self, rhs) {
-            (Self::Union(lhs), Self::Union(rhs)) => {
-                lhs.retain(|ty| rhs.contains(ty));
-            }
-            (Self::Union(union), Self::Complement(complement)) => {
-                union.retain(|ty| !complement.contains(ty));
-            }
-            (Self::Complement(_), Self::Union(_)) => unreachable!(),
-            (Self::Complement(lhs), Self::Complement(mut rhs)) => {
-                lhs.append(&mut rhs);
-            }
-        }
-    }
-}
-
-impl<T: Ord + Clone> BitAndAssign<&InfBTreeSet<T>> for InfBTreeSet<T> {
-    fn bitand_assign(&mut self, rhs: &InfBTreeSet<T>) {
-        if let (InfBTreeSet::Complement(complement), InfBTreeSet::Union(union)) = (&self, rhs) {
-            let mut union = union.clone();
-            union.retain(|ty| !complement.contains(ty));
-            *self = Self::Union(union);
-            return;
-        }
-        match (self, rhs) {
-            (Self::Union(lhs), Self::Union(rhs)) => {
-                lhs.retain(|ty| rhs.contains(ty));
-            }
-            (Self::Union(union), Self::Complement(complement)) => {
-                union.retain(|ty| !complement.contains(ty));
-            }
-            (Self::Complement(_), Self::Union(_)) => unreachable!(),
-            (Self::Complement(lhs), Self::Complement(rhs)) => {
-                lhs.append(&mut rhs.clone());
-            }
-        }
-    }
-}
@@ -0,0 +1,148 @@
+use std::{borrow::Borrow, collections::HashSet, hash::Hash};
+
+use crate::{convert::FromComplement, set::InfSet};
+
+/// A set that can not only represent the union of elements, but also the complement of a
+/// theoretical infinite set, backed by a [`HashSet`].
+///
+/// Prefer this over [`InfBTreeSet`] when all that is needed is membership testing, as a
+/// [`HashSet`] offers O(1) average time complexity instead of the O(log n) of a [`BTreeSet`].
+///
+/// See [`InfSet`] for the full set of guarantees and caveats that come with the "infinite" part.
+///
+/// # Examples
+///
+/// ```
+/// use infset::{convert::FromComplement, hash::InfHashSet};
+///
+/// let mut a = InfHashSet::from([1, 2]);
+/// let b = InfHashSet::from([2, 3]);
+///
+/// assert_eq!(a.clone() | b.clone(), InfHashSet::from([1, 2, 3]));
+/// assert_eq!(a.clone() & b.clone(), InfHashSet::from([2]));
+/// assert_eq!(a.clone() - b.clone(), InfHashSet::from([1]));
+/// assert_eq!(a.clone() ^ b.clone(), InfHashSet::from([1, 3]));
+///
+/// assert!(InfHashSet::from([1]).is_subset(&a));
+/// assert!(!a.is_subset(&InfHashSet::from([1])));
+///
+/// a.negate();
+/// assert_eq!(a, InfHashSet::from_complement([1, 2]));
+/// ```
+///
+/// [`InfBTreeSet`]: crate::btree::InfBTreeSet
+/// [`BTreeSet`]: std::collections::BTreeSet
+pub type InfHashSet<T> = InfSet<HashSet<T>>;
+
+impl<T> InfHashSet<T> {
+    /// Makes a new, empty [`InfHashSet`].
+    ///
+    /// Does not allocate anything on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(unused_mut)]
+    /// use infset::hash::InfHashSet;
+    ///
+    /// let mut set: InfHashSet<i32> = InfHashSet::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::Union(HashSet::new())
+    }
+
+    /// Makes a new [`InfHashSet`] containing "all" values.
+    ///
+    /// Does not allocate anything on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(unused_mut)]
+    /// use infset::hash::InfHashSet;
+    ///
+    /// let mut set: InfHashSet<i32> = InfHashSet::all();
+    /// ```
+    pub fn all() -> Self {
+        Self::Complement(HashSet::new())
+    }
+
+    /// Clears the set, removing all elements.
+    ///
+    /// If the set is currently a [`Complement`], it will be changed to an empty [`Union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::hash::InfHashSet;
+    ///
+    /// let mut set = InfHashSet::from([1, 2, 3]);
+    /// set.clear();
+    /// assert!(set.is_empty());
+    ///
+    /// let mut set = InfHashSet::<i32>::all();
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns `true` if the set contains an element equal to the value.
+    ///
+    /// The value may be any borrowed form of the set's element type, but [`Hash`] and [`Eq`] on
+    /// the borrowed form *must* match those of the element type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{convert::FromComplement, hash::InfHashSet};
+    ///
+    /// let union = InfHashSet::from([42]);
+    /// assert!(union.contains(&42));
+    /// assert!(!union.contains(&256));
+    ///
+    /// let complement = InfHashSet::from_complement([42]);
+    /// assert!(!complement.contains(&42));
+    /// assert!(complement.contains(&256));
+    /// ```
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Eq + Hash,
+        Q: Eq + Hash + ?Sized,
+    {
+        match self {
+            Self::Union(union) => union.contains(value),
+            Self::Complement(complement) => !complement.contains(value),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone, const N: usize> From<[T; N]> for InfHashSet<T> {
+    fn from(arr: [T; N]) -> Self {
+        Self::Union(HashSet::from(arr))
+    }
+}
+
+impl<T: Eq + Hash + Clone> FromIterator<T> for InfHashSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::Union(HashSet::from_iter(iter))
+    }
+}
+
+impl<T: Eq + Hash + Clone, const N: usize> FromComplement<[T; N]> for InfHashSet<T> {
+    fn from_complement(arr: [T; N]) -> Self {
+        Self::Complement(HashSet::from(arr))
+    }
+}
+
+impl<T: Eq + Hash + Clone> TryFrom<InfHashSet<T>> for HashSet<T> {
+    type Error = InfHashSet<T>;
+
+    fn try_from(value: InfHashSet<T>) -> Result<Self, Self::Error> {
+        value.try_into_union()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod btree;
+pub mod convert;
+pub mod hash;
+pub mod set;
+pub mod storage;
@@ -0,0 +1,711 @@
+use std::{
+    mem::swap,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
+};
+
+use crate::{convert::FromComplement, storage::InfSetStorage};
+
+/// A set that can not only represent the union of elements, but also the complement of a
+/// theoretical infinite set, generic over its backing storage `S`.
+///
+/// This is the generic form backing both [`InfBTreeSet`] and [`InfHashSet`]; see those type
+/// aliases for the concrete sets most code will actually use.
+///
+/// It is assumed, that the set of elements, representable by `S::Item`, is infinite.
+/// Say we choose `bool` for our element type which only has `true` and `false` as possible
+/// elements:
+/// Since we know, that the set of possible elements for `bool` is not infinite, one could assume
+/// that `InfSet::from_complement([false, true])` should be equal to the empty set.
+/// This however is not the case, as an empty complement is instead seen as containing literally
+/// "everything", and not just everything representable by `S::Item`.
+///
+/// With this in mind, it usually makes more sense to choose a type that actually does have an
+/// infinite number of possible elements.
+/// Examples for this would be a recursive structure or even just a [`Vec<T>`].
+///
+/// It can also make sense to use integers (or floats), which might not have an infinite number of
+/// actually representable values, but are usually assumed to be a representation of the entire set
+/// of all integers up to infinity.
+///
+/// With the `serde` feature enabled, this serializes as an externally tagged representation,
+/// e.g. `{"union": [1, 2, 3]}` or `{"complement": [1, 2, 3]}`, so the two variants never get
+/// confused with a bare array.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use infset::btree::InfBTreeSet;
+///
+/// let union: InfBTreeSet<i32> = InfBTreeSet::from([1, 2, 3]);
+/// let json = serde_json::to_string(&union).unwrap();
+/// assert_eq!(json, r#"{"union":[1,2,3]}"#);
+/// assert_eq!(serde_json::from_str::<InfBTreeSet<i32>>(&json).unwrap(), union);
+///
+/// let complement: InfBTreeSet<i32> = union.complement();
+/// let json = serde_json::to_string(&complement).unwrap();
+/// assert_eq!(json, r#"{"complement":[1,2,3]}"#);
+/// assert_eq!(serde_json::from_str::<InfBTreeSet<i32>>(&json).unwrap(), complement);
+///
+/// // A bare array is neither tag, so it is rejected rather than silently read as a union.
+/// assert!(serde_json::from_str::<InfBTreeSet<i32>>("[1, 2, 3]").is_err());
+/// # }
+/// ```
+///
+/// [`InfBTreeSet`]: crate::btree::InfBTreeSet
+/// [`InfHashSet`]: crate::hash::InfHashSet
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum InfSet<S> {
+    /// Elements that are part of the set.
+    Union(S),
+    /// Elements that are *not* part of the set.
+    Complement(S),
+}
+
+impl<S> InfSet<S> {
+    pub fn is_empty(&self) -> bool
+    where
+        S: InfSetStorage,
+    {
+        self.as_union().is_some_and(|union| union.is_empty())
+    }
+
+    pub fn is_all(&self) -> bool
+    where
+        S: InfSetStorage,
+    {
+        self.as_complement()
+            .is_some_and(|complement| complement.is_empty())
+    }
+
+    pub fn is_union(&self) -> bool {
+        matches!(self, Self::Union(_))
+    }
+
+    pub fn as_union(&self) -> Option<&S> {
+        if let Self::Union(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn try_into_union(self) -> Result<S, Self> {
+        if let Self::Union(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
+    pub fn is_complement(&self) -> bool {
+        matches!(self, Self::Complement(_))
+    }
+
+    pub fn as_complement(&self) -> Option<&S> {
+        if let Self::Complement(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn try_into_complement(self) -> Result<S, Self> {
+        if let Self::Complement(v) = self {
+            Ok(v)
+        } else {
+            Err(self)
+        }
+    }
+
+    pub fn as_storage(&self) -> &S {
+        let (Self::Union(storage) | Self::Complement(storage)) = self;
+        storage
+    }
+
+    pub fn into_storage(self) -> S {
+        let (Self::Union(storage) | Self::Complement(storage)) = self;
+        storage
+    }
+
+    /// Returns `true` if [`self`] has no elements in common with `other`.
+    ///
+    /// A [`Union`] and [`Complement`] are disjoint, if the [`Union`] is a subset of the
+    /// [`Complement`]'s elements.
+    ///
+    /// Two [`Complement`]s will never be disjoint, as they always have an overlap because of
+    /// their "infinite" nature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let a = InfBTreeSet::from([1, 2]);
+    /// let b = InfBTreeSet::from([3, 4]);
+    /// assert!(a.is_disjoint(&b));
+    ///
+    /// let union = InfBTreeSet::from([1, 2]);
+    /// let complement = InfBTreeSet::from_complement([1, 2, 3]);
+    /// assert!(union.is_disjoint(&complement));
+    ///
+    /// let all = InfBTreeSet::<i32>::all();
+    /// assert!(!all.is_disjoint(&all));
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn is_disjoint(&self, other: &Self) -> bool
+    where
+        S: InfSetStorage,
+    {
+        match (self, other) {
+            (Self::Union(this), Self::Union(other)) => this.is_disjoint(other),
+            (Self::Union(union), Self::Complement(complement))
+            | (Self::Complement(complement), Self::Union(union)) => union.is_subset(complement),
+            (Self::Complement(_), Self::Complement(_)) => false,
+        }
+    }
+
+    /// Returns `true` if the set is a subset of another, i.e., `other` contains at least all the
+    /// elements in `self`.
+    ///
+    /// A [`Union`] is a subset of a [`Complement`] iff the two are disjoint, i.e. the
+    /// [`Union`]'s elements are not excluded by the [`Complement`].
+    ///
+    /// A [`Complement`] is never a subset of a [`Union`], since a [`Complement`] is always
+    /// infinite, while a [`Union`] is always finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let a = InfBTreeSet::from([1, 2]);
+    /// let b = InfBTreeSet::from([1, 2, 3]);
+    /// assert!(a.is_subset(&b));
+    ///
+    /// let union = InfBTreeSet::from([1, 2]);
+    /// let complement = InfBTreeSet::from_complement([3, 4]);
+    /// assert!(union.is_subset(&complement));
+    ///
+    /// let all = InfBTreeSet::<i32>::all();
+    /// let finite = InfBTreeSet::from([1]);
+    /// assert!(!all.is_subset(&finite));
+    ///
+    /// // The more a complement excludes, the fewer elements it logically contains, so excluding
+    /// // a superset of values makes it the *subset*.
+    /// let excludes_one = InfBTreeSet::from_complement([1]);
+    /// let excludes_one_and_two = InfBTreeSet::from_complement([1, 2]);
+    /// assert!(excludes_one_and_two.is_subset(&excludes_one));
+    /// assert!(!excludes_one.is_subset(&excludes_one_and_two));
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn is_subset(&self, other: &Self) -> bool
+    where
+        S: InfSetStorage,
+    {
+        match (self, other) {
+            (Self::Union(this), Self::Union(other)) => this.is_subset(other),
+            (Self::Union(union), Self::Complement(complement)) => union.is_disjoint(complement),
+            (Self::Complement(_), Self::Union(_)) => false,
+            (Self::Complement(this), Self::Complement(other)) => other.is_subset(this),
+        }
+    }
+
+    /// Returns `true` if the set is a superset of another, i.e., `self` contains at least all the
+    /// elements in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::btree::InfBTreeSet;
+    ///
+    /// let a = InfBTreeSet::from([1, 2, 3]);
+    /// let b = InfBTreeSet::from([1, 2]);
+    /// assert!(a.is_superset(&b));
+    /// assert!(!b.is_superset(&a));
+    /// ```
+    pub fn is_superset(&self, other: &Self) -> bool
+    where
+        S: InfSetStorage,
+    {
+        other.is_subset(self)
+    }
+
+    pub fn insert(&mut self, value: S::Item)
+    where
+        S: InfSetStorage,
+    {
+        match self {
+            Self::Union(set) => {
+                set.insert(value);
+            }
+            Self::Complement(set) => {
+                set.remove(&value);
+            }
+        }
+    }
+
+    /// Negates the set in place, turning a [`Union`] into a [`Complement`] and vice versa.
+    ///
+    /// Reuses the existing storage, so no reallocation takes place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let mut set = InfBTreeSet::from([1, 2]);
+    /// set.negate();
+    /// assert_eq!(set, InfBTreeSet::from_complement([1, 2]));
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn negate(&mut self)
+    where
+        S: Default,
+    {
+        match self {
+            Self::Union(set) => {
+                let set = std::mem::take(set);
+                *self = Self::Complement(set);
+            }
+            Self::Complement(set) => {
+                let set = std::mem::take(set);
+                *self = Self::Union(set);
+            }
+        }
+    }
+
+    /// Returns the complement of the set, turning a [`Union`] into a [`Complement`] and vice
+    /// versa.
+    ///
+    /// Reuses the existing storage, so no reallocation takes place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infset::{btree::InfBTreeSet, convert::FromComplement};
+    ///
+    /// let set = InfBTreeSet::from([1, 2]).complement();
+    /// assert_eq!(set, InfBTreeSet::from_complement([1, 2]));
+    /// ```
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    pub fn complement(self) -> Self {
+        match self {
+            Self::Union(set) => Self::Complement(set),
+            Self::Complement(set) => Self::Union(set),
+        }
+    }
+}
+
+impl<S> Not for InfSet<S> {
+    type Output = Self;
+
+    /// Negates the set, turning a [`Union`] into a [`Complement`] and vice versa.
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<S: Clone> Not for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    /// Negates the set, turning a [`Union`] into a [`Complement`] and vice versa.
+    ///
+    /// [`Union`]: InfSet::Union
+    /// [`Complement`]: InfSet::Complement
+    fn not(self) -> Self::Output {
+        self.clone().complement()
+    }
+}
+
+impl<S: InfSetStorage> From<S> for InfSet<S> {
+    fn from(v: S) -> Self {
+        Self::Union(v)
+    }
+}
+
+impl<S: InfSetStorage> FromComplement<S> for InfSet<S> {
+    fn from_complement(v: S) -> Self {
+        Self::Complement(v)
+    }
+}
+
+impl<S: std::fmt::Debug> std::fmt::Debug for InfSet<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_complement() {
+            write!(f, "!")?;
+        }
+        self.as_storage().fmt(f)
+    }
+}
+
+impl<S: InfSetStorage> Default for InfSet<S> {
+    /// Creates an empty `InfSet`.
+    fn default() -> Self {
+        Self::Union(S::default())
+    }
+}
+
+impl<S: InfSetStorage> BitOr for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (InfSet::Union(lhs), InfSet::Union(rhs)) => InfSet::Union(lhs.union(rhs)),
+            (InfSet::Union(union), InfSet::Complement(complement))
+            | (InfSet::Complement(complement), InfSet::Union(union)) => {
+                InfSet::Complement(complement.difference(union))
+            }
+            (InfSet::Complement(lhs), InfSet::Complement(rhs)) => {
+                InfSet::Complement(lhs.intersection(rhs))
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitOr<InfSet<S>> for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn bitor(self, rhs: InfSet<S>) -> Self::Output {
+        rhs | self
+    }
+}
+
+impl<S: InfSetStorage> BitOr<&InfSet<S>> for InfSet<S> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: &InfSet<S>) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> BitOr for InfSet<S> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> BitOrAssign for InfSet<S> {
+    fn bitor_assign(&mut self, mut rhs: Self) {
+        if let (Self::Union(_), Self::Complement(_)) = (&self, &rhs) {
+            swap(self, &mut rhs);
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(mut rhs)) => {
+                lhs.append(&mut rhs);
+            }
+            (Self::Complement(complement), Self::Union(union)) => {
+                complement.retain(|v| !union.contains(v));
+            }
+            (Self::Union(_), Self::Complement(_)) => unreachable!(),
+            (Self::Complement(lhs), Self::Complement(rhs)) => {
+                lhs.retain(|v| rhs.contains(v));
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitOrAssign<&InfSet<S>> for InfSet<S> {
+    fn bitor_assign(&mut self, rhs: &InfSet<S>) {
+        if let (Self::Union(union), Self::Complement(complement)) = (&self, rhs) {
+            let mut complement = complement.clone();
+            complement.retain(|v| !union.contains(v));
+            *self = Self::Complement(complement);
+            return;
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(rhs)) => {
+                lhs.append(&mut rhs.clone());
+            }
+            (Self::Union(_), Self::Complement(_)) => unreachable!(),
+            (Self::Complement(complement), Self::Union(union)) => {
+                complement.retain(|v| !union.contains(v));
+            }
+            (Self::Complement(lhs), Self::Complement(rhs)) => {
+                lhs.retain(|v| rhs.contains(v));
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitAnd for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (InfSet::Union(lhs), InfSet::Union(rhs)) => InfSet::Union(lhs.intersection(rhs)),
+            (InfSet::Union(union), InfSet::Complement(complement))
+            | (InfSet::Complement(complement), InfSet::Union(union)) => {
+                InfSet::Union(union.difference(complement))
+            }
+            (InfSet::Complement(lhs), InfSet::Complement(rhs)) => {
+                InfSet::Complement(lhs.union(rhs))
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitAnd<InfSet<S>> for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn bitand(self, rhs: InfSet<S>) -> Self::Output {
+        rhs & self
+    }
+}
+
+impl<S: InfSetStorage> BitAnd<&InfSet<S>> for InfSet<S> {
+    type Output = Self;
+
+    fn bitand(mut self, rhs: &InfSet<S>) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> BitAnd for InfSet<S> {
+    type Output = Self;
+
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> BitAndAssign for InfSet<S> {
+    fn bitand_assign(&mut self, mut rhs: Self) {
+        if let (Self::Complement(_), Self::Union(_)) = (&self, &rhs) {
+            swap(self, &mut rhs);
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(rhs)) => {
+                lhs.retain(|v| rhs.contains(v));
+            }
+            (Self::Union(union), Self::Complement(complement)) => {
+                union.retain(|v| !complement.contains(v));
+            }
+            (Self::Complement(_), Self::Union(_)) => unreachable!(),
+            (Self::Complement(lhs), Self::Complement(mut rhs)) => {
+                lhs.append(&mut rhs);
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitAndAssign<&InfSet<S>> for InfSet<S> {
+    fn bitand_assign(&mut self, rhs: &InfSet<S>) {
+        if let (Self::Complement(complement), Self::Union(union)) = (&self, rhs) {
+            let mut union = union.clone();
+            union.retain(|v| !complement.contains(v));
+            *self = Self::Union(union);
+            return;
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(rhs)) => {
+                lhs.retain(|v| rhs.contains(v));
+            }
+            (Self::Union(union), Self::Complement(complement)) => {
+                union.retain(|v| !complement.contains(v));
+            }
+            (Self::Complement(_), Self::Union(_)) => unreachable!(),
+            (Self::Complement(lhs), Self::Complement(rhs)) => {
+                lhs.append(&mut rhs.clone());
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> Sub for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (InfSet::Union(lhs), InfSet::Union(rhs)) => InfSet::Union(lhs.difference(rhs)),
+            (InfSet::Union(lhs), InfSet::Complement(rhs)) => InfSet::Union(lhs.intersection(rhs)),
+            (InfSet::Complement(lhs), InfSet::Union(rhs)) => InfSet::Complement(lhs.union(rhs)),
+            (InfSet::Complement(lhs), InfSet::Complement(rhs)) => {
+                InfSet::Union(rhs.difference(lhs))
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> Sub<InfSet<S>> for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn sub(self, rhs: InfSet<S>) -> Self::Output {
+        self.clone() - rhs
+    }
+}
+
+impl<S: InfSetStorage> Sub<&InfSet<S>> for InfSet<S> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: &InfSet<S>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> Sub for InfSet<S> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> SubAssign for InfSet<S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if let (Self::Complement(_), Self::Complement(_)) = (&*self, &rhs) {
+            match (&mut *self, rhs) {
+                (Self::Complement(lhs), Self::Complement(mut rhs)) => {
+                    rhs.retain(|v| !lhs.contains(v));
+                    *self = Self::Union(rhs);
+                }
+                _ => unreachable!(),
+            }
+            return;
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(rhs)) => {
+                lhs.retain(|v| !rhs.contains(v));
+            }
+            (Self::Union(lhs), Self::Complement(rhs)) => {
+                lhs.retain(|v| rhs.contains(v));
+            }
+            (Self::Complement(lhs), Self::Union(mut rhs)) => {
+                lhs.append(&mut rhs);
+            }
+            (Self::Complement(_), Self::Complement(_)) => unreachable!(),
+        }
+    }
+}
+
+impl<S: InfSetStorage> SubAssign<&InfSet<S>> for InfSet<S> {
+    fn sub_assign(&mut self, rhs: &InfSet<S>) {
+        if let (Self::Complement(lhs), Self::Complement(rhs)) = (&*self, rhs) {
+            let mut rhs = rhs.clone();
+            rhs.retain(|v| !lhs.contains(v));
+            *self = Self::Union(rhs);
+            return;
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(rhs)) => {
+                lhs.retain(|v| !rhs.contains(v));
+            }
+            (Self::Union(lhs), Self::Complement(rhs)) => {
+                lhs.retain(|v| rhs.contains(v));
+            }
+            (Self::Complement(lhs), Self::Union(union)) => {
+                lhs.append(&mut union.clone());
+            }
+            (Self::Complement(_), Self::Complement(_)) => unreachable!(),
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitXor for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (InfSet::Union(lhs), InfSet::Union(rhs)) => {
+                InfSet::Union(lhs.symmetric_difference(rhs))
+            }
+            (InfSet::Union(lhs), InfSet::Complement(rhs))
+            | (InfSet::Complement(rhs), InfSet::Union(lhs)) => {
+                InfSet::Complement(lhs.symmetric_difference(rhs))
+            }
+            (InfSet::Complement(lhs), InfSet::Complement(rhs)) => {
+                InfSet::Union(lhs.symmetric_difference(rhs))
+            }
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitXor<InfSet<S>> for &InfSet<S> {
+    type Output = InfSet<S>;
+
+    fn bitxor(self, rhs: InfSet<S>) -> Self::Output {
+        rhs ^ self
+    }
+}
+
+impl<S: InfSetStorage> BitXor<&InfSet<S>> for InfSet<S> {
+    type Output = Self;
+
+    fn bitxor(mut self, rhs: &InfSet<S>) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> BitXor for InfSet<S> {
+    type Output = Self;
+
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+
+impl<S: InfSetStorage> BitXorAssign for InfSet<S> {
+    fn bitxor_assign(&mut self, mut rhs: Self) {
+        if let (Self::Union(_), Self::Complement(_)) = (&self, &rhs) {
+            swap(self, &mut rhs);
+        }
+        match (&mut *self, rhs) {
+            (Self::Union(lhs), Self::Union(rhs)) | (Self::Complement(lhs), Self::Union(rhs)) => {
+                *lhs = lhs.symmetric_difference(&rhs);
+            }
+            (Self::Complement(lhs), Self::Complement(rhs)) => {
+                let result = lhs.symmetric_difference(&rhs);
+                *self = Self::Union(result);
+            }
+            (Self::Union(_), Self::Complement(_)) => unreachable!(),
+        }
+    }
+}
+
+impl<S: InfSetStorage> BitXorAssign<&InfSet<S>> for InfSet<S> {
+    fn bitxor_assign(&mut self, rhs: &InfSet<S>) {
+        if let (Self::Union(union), Self::Complement(complement)) = (&self, rhs) {
+            let result = union.symmetric_difference(complement);
+            *self = Self::Complement(result);
+            return;
+        }
+        if let (Self::Complement(lhs), Self::Complement(rhs)) = (&self, rhs) {
+            let result = lhs.symmetric_difference(rhs);
+            *self = Self::Union(result);
+            return;
+        }
+        match (self, rhs) {
+            (Self::Union(lhs), Self::Union(other))
+            | (Self::Complement(lhs), Self::Union(other)) => {
+                *lhs = lhs.symmetric_difference(other);
+            }
+            (Self::Union(_), Self::Complement(_)) | (Self::Complement(_), Self::Complement(_)) => {
+                unreachable!()
+            }
+        }
+    }
+}
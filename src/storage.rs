@@ -0,0 +1,165 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+/// The backing storage used by [`InfSet`] to hold either the elements of a [`Union`] or the
+/// excluded elements of a [`Complement`].
+///
+/// This is what lets [`InfSet`] stay generic over the actual container: the Union/Complement
+/// algebra only ever needs membership, insertion, removal, retaining, appending, the emptiness,
+/// disjointness and subset predicates and the four binary set operations, so any container
+/// providing those can back an [`InfSet`].
+///
+/// [`InfSet`]: crate::set::InfSet
+/// [`Union`]: crate::set::InfSet::Union
+/// [`Complement`]: crate::set::InfSet::Complement
+pub trait InfSetStorage: Default + Clone {
+    /// The type of element stored.
+    type Item;
+
+    /// Returns `true` if the storage contains an element equal to the value.
+    fn contains(&self, value: &Self::Item) -> bool;
+
+    /// Adds a value to the storage. Returns whether the value was newly inserted.
+    fn insert(&mut self, value: Self::Item) -> bool;
+
+    /// Removes a value from the storage. Returns whether the value was previously contained.
+    fn remove(&mut self, value: &Self::Item) -> bool;
+
+    /// Retains only the elements for which `f` returns `true`.
+    fn retain(&mut self, f: impl FnMut(&Self::Item) -> bool);
+
+    /// Moves all elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// Used by the in-place `Bit*Assign`/`SubAssign` impls on [`InfSet`] to combine two operands
+    /// without allocating a new storage.
+    ///
+    /// [`InfSet`]: crate::set::InfSet
+    fn append(&mut self, other: &mut Self);
+
+    /// Returns `true` if the storage contains no elements.
+    fn is_empty(&self) -> bool;
+
+    /// Returns `true` if `self` has no elements in common with `other`.
+    fn is_disjoint(&self, other: &Self) -> bool;
+
+    /// Returns `true` if `self` is a subset of `other`.
+    fn is_subset(&self, other: &Self) -> bool;
+
+    /// Returns the union of `self` and `other`.
+    fn union(&self, other: &Self) -> Self;
+
+    /// Returns the intersection of `self` and `other`.
+    fn intersection(&self, other: &Self) -> Self;
+
+    /// Returns the elements of `self` that are not in `other`.
+    fn difference(&self, other: &Self) -> Self;
+
+    /// Returns the elements that are in `self` or `other` but not both.
+    fn symmetric_difference(&self, other: &Self) -> Self;
+}
+
+impl<T: Ord + Clone> InfSetStorage for BTreeSet<T> {
+    type Item = T;
+
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        BTreeSet::insert(self, value)
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        BTreeSet::remove(self, value)
+    }
+
+    fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        BTreeSet::retain(self, f);
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        BTreeSet::append(self, other);
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        BTreeSet::is_disjoint(self, other)
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        BTreeSet::is_subset(self, other)
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn symmetric_difference(&self, other: &Self) -> Self {
+        self ^ other
+    }
+}
+
+impl<T: Eq + Hash + Clone> InfSetStorage for HashSet<T> {
+    type Item = T;
+
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+
+    fn insert(&mut self, value: T) -> bool {
+        HashSet::insert(self, value)
+    }
+
+    fn remove(&mut self, value: &T) -> bool {
+        HashSet::remove(self, value)
+    }
+
+    fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        HashSet::retain(self, f);
+    }
+
+    fn append(&mut self, other: &mut Self) {
+        // `HashSet` has no `append` of its own, unlike `BTreeSet`; draining `other` into `self`
+        // has the same effect of moving the elements over and leaving `other` empty.
+        self.extend(other.drain());
+    }
+
+    fn is_empty(&self) -> bool {
+        HashSet::is_empty(self)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        HashSet::is_disjoint(self, other)
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        HashSet::is_subset(self, other)
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn symmetric_difference(&self, other: &Self) -> Self {
+        self ^ other
+    }
+}